@@ -1,42 +1,174 @@
-use reqwest::{Client, StatusCode};
+use rand::Rng;
+use secrecy::{ExposeSecret, Secret};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::fmt;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "solana-signer")]
+mod signer;
+mod transport;
+
+#[cfg(feature = "solana-signer")]
+pub use signer::Nlx402Signer;
+#[cfg(feature = "reqwest-transport")]
+pub use transport::ReqwestTransport;
+pub use transport::{HttpMethod, HttpTransport, RequestBody};
 
 #[derive(Debug)]
-pub enum Nlx402Error {r
+pub enum Nlx402Error {
+    #[cfg(feature = "reqwest-transport")]
     Http(reqwest::Error),
     Api {
         status: u16,
         body: Option<Value>,
+        /// The error body decoded into `ApiProblem`, when it matched that shape.
+        problem: Option<ApiProblem>,
+        /// Number of HTTP attempts made, including the one that produced
+        /// this error (1 if no retry occurred).
+        attempts: u32,
     },
     MissingApiKey,
+    /// No `HttpTransport` was supplied in `Nlx402ClientOptions` and the
+    /// `reqwest-transport` feature (which would otherwise provide a default)
+    /// is disabled.
+    MissingTransport,
     InvalidResponse(String),
+    Signer(String),
+    /// The paid-access poll reached a terminal failure status (e.g.
+    /// `"failed"`/`"rejected"`) instead of confirming.
+    PaymentRejected(String),
+    /// Polling exceeded `PollConfig.timeout` or the quote expired before the
+    /// payment reached a terminal status.
+    PollTimeout,
 }
 
 impl fmt::Display for Nlx402Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            #[cfg(feature = "reqwest-transport")]
             Nlx402Error::Http(err) => write!(f, "HTTP error: {}", err),
-            Nlx402Error::Api { status, .. } => {
-                write!(f, "NLx402 request failed with status {}", status)
+            Nlx402Error::Api {
+                status, attempts, ..
+            } => {
+                write!(
+                    f,
+                    "NLx402 request failed with status {} after {} attempt(s)",
+                    status, attempts
+                )
             }
             Nlx402Error::MissingApiKey => {
                 write!(f, "NLx402: API key is required but not set.")
             }
+            Nlx402Error::MissingTransport => {
+                write!(
+                    f,
+                    "NLx402: no HttpTransport configured (enable the `reqwest-transport` \
+                     feature or supply Nlx402ClientOptions.transport)"
+                )
+            }
             Nlx402Error::InvalidResponse(msg) => write!(f, "Invalid response: {}", msg),
+            Nlx402Error::Signer(msg) => write!(f, "NLx402 signer error: {}", msg),
+            Nlx402Error::PaymentRejected(status) => {
+                write!(f, "NLx402 payment was not confirmed (status: {})", status)
+            }
+            Nlx402Error::PollTimeout => {
+                write!(f, "NLx402: timed out waiting for payment confirmation")
+            }
         }
     }
 }
 
 impl std::error::Error for Nlx402Error {}
 
+#[cfg(feature = "reqwest-transport")]
 impl From<reqwest::Error> for Nlx402Error {
     fn from(err: reqwest::Error) -> Self {
         Nlx402Error::Http(err)
     }
 }
 
+impl Nlx402Error {
+    /// The `code` field of the decoded error body, when the error is an
+    /// `Api` error whose body matched the `ApiProblem` shape.
+    fn problem_code(&self) -> Option<&str> {
+        match self {
+            Nlx402Error::Api {
+                problem: Some(problem),
+                ..
+            } => problem.code.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Whether the server rejected the request because the payer's balance
+    /// couldn't cover the quoted amount.
+    pub fn is_insufficient_funds(&self) -> bool {
+        self.problem_code()
+            .is_some_and(|code| code.eq_ignore_ascii_case("insufficient_funds"))
+    }
+
+    /// Whether the server rejected the request because the quote it
+    /// referenced had already expired.
+    pub fn is_expired_quote(&self) -> bool {
+        self.problem_code().is_some_and(|code| {
+            code.eq_ignore_ascii_case("expired_quote") || code.eq_ignore_ascii_case("quote_expired")
+        })
+    }
+
+    /// Whether the request was rejected for exceeding the server's rate limit.
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self, Nlx402Error::Api { status: 429, .. })
+            || self
+                .problem_code()
+                .is_some_and(|code| code.eq_ignore_ascii_case("rate_limited"))
+    }
+}
+
+/// Structured shape of an NLx402 API error body, analogous to ACME's
+/// `Problem` type. Any field the server omits is simply `None`; callers that
+/// need data outside this shape can still fall back to `Nlx402Error::Api.body`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ApiProblem {
+    pub code: Option<String>,
+    pub message: Option<String>,
+    pub detail: Option<String>,
+}
+
+/// Current unix time in seconds, used for quote-expiry and poll-deadline checks.
+fn now_unix() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// Recognizes the server's stale/invalid-nonce error shape: either the
+/// legacy `{"status": "badNonce"}` / `{"error": "nonce_expired"}` string
+/// fields, or the `ApiProblem.code` vocabulary (e.g. `{"code": "bad_nonce"}`).
+fn is_stale_nonce_error(body: &Option<Value>, problem: &Option<ApiProblem>) -> bool {
+    fn matches_marker(s: &str) -> bool {
+        let s = s.to_ascii_lowercase();
+        s.contains("badnonce")
+            || s.contains("bad_nonce")
+            || s.contains("nonce_expired")
+            || s.contains("stale_nonce")
+    }
+
+    let body_marker = body.as_ref().is_some_and(|b| {
+        ["status", "error"]
+            .iter()
+            .any(|field| b.get(field).and_then(Value::as_str).is_some_and(matches_marker))
+    });
+
+    let code_marker = problem
+        .as_ref()
+        .and_then(|p| p.code.as_deref())
+        .is_some_and(matches_marker);
+
+    body_marker || code_marker
+}
+
 /// AuthMeResponse (GET /api/auth/me)
 #[derive(Debug, Deserialize, Serialize)]
 pub struct AuthMeResponse {
@@ -108,86 +240,283 @@ pub struct QuoteAndVerify {
     pub verify: VerifyResponse,
 }
 
+/// Tuning for `poll_until_confirmed`'s exponential-backoff loop.
+#[derive(Debug, Clone)]
+pub struct PollConfig {
+    /// Give up and return `Nlx402Error::PollTimeout` after this much wall time.
+    pub timeout: Duration,
+    /// Backoff cap for the first retry; the actual delay is drawn uniformly
+    /// between zero and this value.
+    pub base_delay: Duration,
+    /// Upper bound the backoff is capped at before jitter is applied.
+    pub max_delay: Duration,
+    /// The polled quote's `expires_at` (unix seconds). When set, polling
+    /// stops early with `Nlx402Error::PollTimeout` once it passes, since a
+    /// `get_paid_access` call can no longer succeed for an expired quote.
+    pub expires_at: Option<i64>,
+}
+
+impl Default for PollConfig {
+    fn default() -> Self {
+        PollConfig {
+            timeout: Duration::from_secs(60),
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(8),
+            expires_at: None,
+        }
+    }
+}
+
+/// Default window, in seconds, before a quote's `expires_at` during which
+/// it is treated as already expired so a refresh has time to complete.
+const DEFAULT_NONCE_SKEW_SECS: u64 = 5;
+
+/// Governs automatic retries of idempotent requests on 429/5xx responses.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Total attempts allowed, including the first. `1` disables retries.
+    pub max_attempts: u32,
+    /// Backoff cap for the first retry, doubled on each subsequent one; the
+    /// actual delay is drawn uniformly between zero and that cap.
+    pub base_delay: Duration,
+    /// Upper bound the backoff is capped at before jitter is applied.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+}
+
 /// Options-like struct for constructing the client.
+#[derive(Default)]
 pub struct Nlx402ClientOptions {
     pub api_key: Option<String>,
     pub base_url: Option<String>,
+    /// Skew window (seconds) applied when checking `QuoteResponse.expires_at`.
+    /// Defaults to `DEFAULT_NONCE_SKEW_SECS` when unset.
+    pub nonce_skew_secs: Option<u64>,
+    /// Retry behavior for idempotent requests. Defaults to `RetryPolicy::default()`.
+    pub retry_policy: Option<RetryPolicy>,
+    /// HTTP transport to issue requests through. Defaults to `ReqwestTransport`
+    /// when the `reqwest-transport` feature is enabled.
+    pub transport: Option<Box<dyn HttpTransport>>,
 }
 
+/// Environment variable consulted for the API key when
+/// `Nlx402ClientOptions.api_key` is left unset.
+const NLX402_API_KEY_ENV: &str = "NLX402_API_KEY";
+
 /// NLx402 Rust client (async).
 pub struct Nlx402Client {
     base_url: String,
-    api_key: Option<String>,
-    http: Client,
+    api_key: Option<Secret<String>>,
+    transport: Box<dyn HttpTransport>,
+    nonce_skew_secs: u64,
+    retry_policy: RetryPolicy,
+}
+
+/// The transport used when `Nlx402ClientOptions.transport` is left unset.
+/// `None` when the `reqwest-transport` feature is disabled and no transport
+/// was supplied, in which case the caller must provide one explicitly.
+#[cfg(feature = "reqwest-transport")]
+fn default_transport() -> Option<Box<dyn HttpTransport>> {
+    Some(Box::new(ReqwestTransport::new()))
+}
+
+#[cfg(not(feature = "reqwest-transport"))]
+fn default_transport() -> Option<Box<dyn HttpTransport>> {
+    None
+}
+
+impl fmt::Debug for Nlx402Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let api_key = if self.api_key.is_some() {
+            "Some([REDACTED])"
+        } else {
+            "None"
+        };
+        f.debug_struct("Nlx402Client")
+            .field("base_url", &self.base_url)
+            .field("api_key", &api_key)
+            .field("nonce_skew_secs", &self.nonce_skew_secs)
+            .finish()
+    }
 }
 
 impl Nlx402Client {
-    pub fn new(options: Nlx402ClientOptions) -> Self {
+    pub fn new(options: Nlx402ClientOptions) -> Result<Self, Nlx402Error> {
         let base_url = options
             .base_url
             .unwrap_or_else(|| "https://pay.thrt.ai".to_string());
         let base_url = base_url.trim_end_matches('/').to_string();
 
-        Nlx402Client {
+        let api_key = options
+            .api_key
+            .or_else(|| std::env::var(NLX402_API_KEY_ENV).ok())
+            .map(Secret::new);
+
+        let transport = match options.transport {
+            Some(transport) => transport,
+            None => default_transport().ok_or(Nlx402Error::MissingTransport)?,
+        };
+
+        Ok(Nlx402Client {
             base_url,
-            api_key: options.api_key,
-            http: Client::new(),
-        }
+            api_key,
+            transport,
+            nonce_skew_secs: options.nonce_skew_secs.unwrap_or(DEFAULT_NONCE_SKEW_SECS),
+            retry_policy: options.retry_policy.unwrap_or_default(),
+        })
     }
 
-    pub fn with_api_key(api_key: impl Into<String>) -> Self {
+    pub fn with_api_key(api_key: impl Into<String>) -> Result<Self, Nlx402Error> {
         Self::new(Nlx402ClientOptions {
             api_key: Some(api_key.into()),
-            base_url: None,
+            ..Default::default()
         })
     }
 
     pub fn set_api_key(&mut self, api_key: impl Into<String>) {
-        self.api_key = Some(api_key.into());
+        self.api_key = Some(Secret::new(api_key.into()));
     }
 
     fn require_api_key(&self) -> Result<&str, Nlx402Error> {
         self.api_key
-            .as_deref()
+            .as_ref()
+            .map(|key| key.expose_secret().as_str())
             .ok_or(Nlx402Error::MissingApiKey)
     }
 
-    async fn send_json<T>(&self, builder: reqwest::RequestBuilder) -> Result<T, Nlx402Error>
+    /// Issues a request through `self.transport` and decodes the JSON
+    /// response. When `retryable` is true, 429 and 5xx responses are retried
+    /// until `retry_policy.max_attempts` attempts (the first one included)
+    /// have been made, with exponential backoff and
+    /// jitter, honoring the transport's parsed `Retry-After` hint instead of
+    /// the backoff delay when one is present.
+    async fn send_json<T>(
+        &self,
+        method: HttpMethod,
+        url: &str,
+        headers: Vec<(&str, String)>,
+        body: Option<RequestBody>,
+        retryable: bool,
+    ) -> Result<T, Nlx402Error>
     where
         T: for<'de> serde::Deserialize<'de>,
     {
-        let res = builder.send().await?;
-        let status = res.status();
-        let text = res.text().await?;
+        let mut attempt: u32 = 0;
+
+        loop {
+            attempt += 1;
+            let response = self
+                .transport
+                .execute(method, url, &headers, body.clone())
+                .await?;
+
+            if response.status.is_success() {
+                return serde_json::from_str::<T>(&response.body)
+                    .map_err(|e| Nlx402Error::InvalidResponse(e.to_string()));
+            }
 
-        if !status.is_success() {
-            let body = serde_json::from_str::<Value>(&text).ok();
+            let is_retryable_status =
+                response.status.as_u16() == 429 || response.status.is_server_error();
+            if retryable && is_retryable_status && attempt < self.retry_policy.max_attempts {
+                let delay = response.retry_after.unwrap_or_else(|| {
+                    backoff_with_jitter(
+                        attempt - 1,
+                        self.retry_policy.base_delay,
+                        self.retry_policy.max_delay,
+                    )
+                });
+                tokio::time::sleep(delay).await;
+                continue;
+            }
+
+            let body = serde_json::from_str::<Value>(&response.body).ok();
+            let problem = body
+                .as_ref()
+                .and_then(|v| serde_json::from_value::<ApiProblem>(v.clone()).ok());
             return Err(Nlx402Error::Api {
-                status: status.as_u16(),
+                status: response.status.as_u16(),
                 body,
+                problem,
+                attempts: attempt,
             });
         }
+    }
+
+    /// Sends the request `build` produces for `quote`, and if the server
+    /// rejects it with a stale/invalid-nonce error, re-fetches a fresh quote
+    /// and retries exactly once with the new nonce.
+    async fn send_json_with_nonce_retry<T>(
+        &self,
+        quote: QuoteResponse,
+        retryable: bool,
+        build: impl Fn(&QuoteResponse) -> Result<(String, Vec<(&'static str, String)>, RequestBody), Nlx402Error>,
+    ) -> Result<T, Nlx402Error>
+    where
+        T: for<'de> serde::Deserialize<'de>,
+    {
+        let (url, headers, body) = build(&quote)?;
+        match self
+            .send_json(HttpMethod::Post, &url, headers, Some(body), retryable)
+            .await
+        {
+            Err(Nlx402Error::Api {
+                status,
+                body,
+                problem,
+                ..
+            }) if (400..500).contains(&status) && is_stale_nonce_error(&body, &problem) =>
+            {
+                let fresh = self.get_quote(Some(quote.amount.clone())).await?;
+                let (url, headers, body) = build(&fresh)?;
+                self.send_json(HttpMethod::Post, &url, headers, Some(body), retryable)
+                    .await
+            }
+            other => other,
+        }
+    }
+
+    /// True once `quote.expires_at` is within `nonce_skew_secs` of now (or
+    /// already past), meaning it should be treated as expired.
+    fn is_quote_stale(&self, quote: &QuoteResponse) -> bool {
+        now_unix() + self.nonce_skew_secs as i64 >= quote.expires_at
+    }
 
-        serde_json::from_str::<T>(&text)
-            .map_err(|e| Nlx402Error::InvalidResponse(e.to_string()))
+    /// Returns `quote` as-is if it still has useful life left, or a freshly
+    /// fetched replacement (with its own nonce) if it has expired or is
+    /// about to.
+    async fn ensure_current_quote(
+        &self,
+        quote: &QuoteResponse,
+    ) -> Result<QuoteResponse, Nlx402Error> {
+        if self.is_quote_stale(quote) {
+            self.get_quote(Some(quote.amount.clone())).await
+        } else {
+            Ok(quote.clone())
+        }
     }
 
     pub async fn get_auth_me(&self) -> Result<AuthMeResponse, Nlx402Error> {
         let api_key = self.require_api_key()?;
         let url = format!("{}/api/auth/me", self.base_url);
+        let headers = vec![("x-api-key", api_key.to_string())];
 
-        let builder = self
-            .http
-            .get(&url)
-            .header("x-api-key", api_key);
-
-        self.send_json(builder).await
+        self.send_json(HttpMethod::Get, &url, headers, None, true)
+            .await
     }
 
     pub async fn get_metadata(&self) -> Result<MetadataResponse, Nlx402Error> {
         let url = format!("{}/api/metadata", self.base_url);
-        let builder = self.http.get(&url);
-        self.send_json(builder).await
+        self.send_json(HttpMethod::Get, &url, vec![], None, true)
+            .await
     }
 
     pub async fn get_quote(
@@ -201,46 +530,54 @@ impl Nlx402Client {
             .map(|t| t.into())
             .unwrap_or_else(|| "0.5".to_string());
 
-        let builder = self
-            .http
-            .get(&url)
-            .header("x-api-key", api_key)
-            .header("x-total-price", total);
+        let headers = vec![("x-api-key", api_key.to_string()), ("x-total-price", total)];
 
-        self.send_json(builder).await
+        self.send_json(HttpMethod::Get, &url, headers, None, true)
+            .await
     }
 
+    /// Submits `quote` for payment verification, using `quote.nonce` (or the
+    /// nonce of the fresh quote fetched in its place if `quote` had gone
+    /// stale) — there is no separate caller-supplied nonce to go out of sync
+    /// with. `allow_retry` opts into automatic retries on 429/5xx; leave it
+    /// `false` (the default via `get_and_verify_quote`) unless verification
+    /// is known to be safe to duplicate, since this is a POST and not
+    /// idempotent on every backend.
     pub async fn verify_quote(
         &self,
         quote: &QuoteResponse,
-        nonce: &str,
+        allow_retry: bool,
     ) -> Result<VerifyResponse, Nlx402Error> {
         let api_key = self.require_api_key()?;
-        if nonce.is_empty() {
+        if quote.nonce.is_empty() {
             return Err(Nlx402Error::InvalidResponse(
-                "verify_quote: nonce is required".into(),
+                "verify_quote: quote.nonce is required".into(),
             ));
         }
 
+        let quote = self.ensure_current_quote(quote).await?;
         let url = format!("{}/verify", self.base_url);
-        let payment_data =
-            serde_json::to_string(quote).map_err(|e| Nlx402Error::InvalidResponse(e.to_string()))?;
-
-        let form = [("payment_data", payment_data), ("nonce", nonce.to_string())];
-
-        let builder = self
-            .http
-            .post(&url)
-            .header("x-api-key", api_key)
-            .form(&form);
 
-        self.send_json(builder).await
+        self.send_json_with_nonce_retry(quote, allow_retry, |q| {
+            let payment_data = serde_json::to_string(q)
+                .map_err(|e| Nlx402Error::InvalidResponse(e.to_string()))?;
+            let headers = vec![("x-api-key", api_key.to_string())];
+            let body = RequestBody::Form(vec![
+                ("payment_data".to_string(), payment_data),
+                ("nonce".to_string(), q.nonce.clone()),
+            ]);
+            Ok((url.clone(), headers, body))
+        })
+        .await
     }
 
+    /// As `verify_quote`, but takes an already-serialized quote body.
+    /// `allow_retry` has the same opt-in-only semantics as `verify_quote`.
     pub async fn verify_quote_raw(
         &self,
         quote_json: &str,
         nonce: &str,
+        allow_retry: bool,
     ) -> Result<VerifyResponse, Nlx402Error> {
         let api_key = self.require_api_key()?;
         if nonce.is_empty() {
@@ -250,17 +587,23 @@ impl Nlx402Client {
         }
 
         let url = format!("{}/verify", self.base_url);
-        let form = [("payment_data", quote_json.to_string()), ("nonce", nonce.to_string())];
-
-        let builder = self
-            .http
-            .post(&url)
-            .header("x-api-key", api_key)
-            .form(&form);
-
-        self.send_json(builder).await
+        let headers = vec![("x-api-key", api_key.to_string())];
+        let body = RequestBody::Form(vec![
+            ("payment_data".to_string(), quote_json.to_string()),
+            ("nonce".to_string(), nonce.to_string()),
+        ]);
+
+        self.send_json(HttpMethod::Post, &url, headers, Some(body), allow_retry)
+            .await
     }
 
+    /// Redeems `tx` (an already-submitted, signed on-chain transaction) for
+    /// protected access. Unlike `verify_quote`, a stale/bad-nonce response
+    /// here is never auto-retried: `tx`'s memo embeds the nonce of the quote
+    /// it paid, so a fresh quote's nonce cannot legitimately be substituted
+    /// for it — a stale nonce at this point can only be fixed by signing and
+    /// submitting a brand-new on-chain payment (e.g. via `pay_and_access`),
+    /// which is outside this call's contract.
     pub async fn get_paid_access(
         &self,
         tx: &str,
@@ -275,14 +618,10 @@ impl Nlx402Client {
 
         let url = format!("{}/protected", self.base_url);
         let x_payment = serde_json::json!({ "tx": tx, "nonce": nonce }).to_string();
+        let headers = vec![("x-api-key", api_key.to_string()), ("x-payment", x_payment)];
 
-        let builder = self
-            .http
-            .get(&url)
-            .header("x-api-key", api_key)
-            .header("x-payment", x_payment);
-
-        self.send_json(builder).await
+        self.send_json(HttpMethod::Get, &url, headers, None, true)
+            .await
     }
 
     pub async fn get_and_verify_quote(
@@ -290,7 +629,165 @@ impl Nlx402Client {
         total_price: Option<impl Into<String>>,
     ) -> Result<QuoteAndVerify, Nlx402Error> {
         let quote = self.get_quote(total_price).await?;
-        let verify = self.verify_quote(&quote, &quote.nonce).await?;
+        let verify = self.verify_quote(&quote, false).await?;
         Ok(QuoteAndVerify { quote, verify })
     }
+
+    /// Completes an entire pay-and-access cycle with one call: fetches a
+    /// quote, signs and submits the SPL-token transfer it describes with
+    /// `signer`, then redeems the resulting transaction for protected
+    /// access. Callers that already hold a signature (e.g. because payment
+    /// happened outside this crate) should keep using `get_paid_access`
+    /// directly. Requires the `solana-signer` feature, which is on by
+    /// default.
+    #[cfg(feature = "solana-signer")]
+    pub async fn pay_and_access(
+        &self,
+        signer: &Nlx402Signer,
+        total_price: Option<impl Into<String>>,
+    ) -> Result<PaidAccessResponse, Nlx402Error> {
+        let quote = self.get_quote(total_price).await?;
+        let tx = signer
+            .sign_and_submit(self.transport.as_ref(), &quote)
+            .await?;
+        self.get_paid_access(&tx, &quote.nonce).await
+    }
+
+    /// Polls `get_paid_access` until the submitted payment reaches a
+    /// terminal status, using exponential backoff with jitter between
+    /// attempts. Returns as soon as `X402Info.status` is `"confirmed"` or
+    /// `"settled"`; returns `Nlx402Error::PaymentRejected` on `"failed"` or
+    /// `"rejected"`; keeps polling on any other (pending) status.
+    pub async fn poll_until_confirmed(
+        &self,
+        tx: &str,
+        nonce: &str,
+        config: PollConfig,
+    ) -> Result<PaidAccessResponse, Nlx402Error> {
+        let start = Instant::now();
+        let mut attempt: u32 = 0;
+
+        loop {
+            if start.elapsed() >= config.timeout {
+                return Err(Nlx402Error::PollTimeout);
+            }
+            if config.expires_at.is_some_and(|exp| now_unix() >= exp) {
+                return Err(Nlx402Error::PollTimeout);
+            }
+
+            let response = self.get_paid_access(tx, nonce).await?;
+            match response.x402.status.as_str() {
+                "confirmed" | "settled" => return Ok(response),
+                "failed" | "rejected" => {
+                    return Err(Nlx402Error::PaymentRejected(response.x402.status))
+                }
+                _ => {}
+            }
+
+            tokio::time::sleep(backoff_with_jitter(attempt, config.base_delay, config.max_delay))
+                .await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Computes `base_delay * 2^attempt`, capped at `max_delay`, then applies
+/// full jitter (a uniformly random delay between zero and that cap).
+fn backoff_with_jitter(attempt: u32, base_delay: Duration, max_delay: Duration) -> Duration {
+    let exponential = base_delay.saturating_mul(1u32 << attempt.min(16));
+    let capped = exponential.min(max_delay);
+    let jitter_ms = rand::thread_rng().gen_range(0..=capped.as_millis().max(1) as u64);
+    Duration::from_millis(jitter_ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_with_jitter_stays_within_cap() {
+        let base = Duration::from_millis(250);
+        let max = Duration::from_secs(5);
+        for attempt in 0..10 {
+            let delay = backoff_with_jitter(attempt, base, max);
+            assert!(delay <= max, "attempt {attempt} exceeded max_delay: {delay:?}");
+        }
+    }
+
+    #[test]
+    fn backoff_with_jitter_grows_with_attempt_before_capping() {
+        let base = Duration::from_millis(100);
+        let max = Duration::from_secs(60);
+        // With full jitter the draw is random, but the *cap* each attempt is
+        // drawn from should still double until it saturates `max`.
+        let delay = backoff_with_jitter(0, base, max);
+        assert!(delay <= base);
+        // At attempt 3 the cap is base * 8, so over many draws some must land
+        // above the attempt-0 cap while none exceed the attempt-3 cap.
+        let draws: Vec<Duration> = (0..200).map(|_| backoff_with_jitter(3, base, max)).collect();
+        assert!(draws.iter().all(|d| *d <= base * 8));
+        assert!(draws.iter().any(|d| *d > base));
+    }
+
+    #[test]
+    fn is_stale_nonce_error_matches_legacy_body_fields() {
+        let body = Some(serde_json::json!({ "status": "badNonce" }));
+        assert!(is_stale_nonce_error(&body, &None));
+
+        let body = Some(serde_json::json!({ "error": "nonce_expired" }));
+        assert!(is_stale_nonce_error(&body, &None));
+
+        let body = Some(serde_json::json!({ "status": "insufficient_funds" }));
+        assert!(!is_stale_nonce_error(&body, &None));
+    }
+
+    #[test]
+    fn is_stale_nonce_error_matches_problem_code() {
+        let problem = Some(ApiProblem {
+            code: Some("bad_nonce".to_string()),
+            message: None,
+            detail: None,
+        });
+        assert!(is_stale_nonce_error(&None, &problem));
+
+        let problem = Some(ApiProblem {
+            code: Some("insufficient_funds".to_string()),
+            message: None,
+            detail: None,
+        });
+        assert!(!is_stale_nonce_error(&None, &problem));
+    }
+
+    #[test]
+    fn is_quote_stale_respects_skew_window() {
+        let client = Nlx402Client::new(Nlx402ClientOptions {
+            nonce_skew_secs: Some(5),
+            ..Default::default()
+        })
+        .expect("default transport is available");
+
+        let mut quote = sample_quote();
+        quote.expires_at = now_unix() + 100;
+        assert!(!client.is_quote_stale(&quote));
+
+        quote.expires_at = now_unix() + 1;
+        assert!(client.is_quote_stale(&quote));
+
+        quote.expires_at = now_unix() - 1;
+        assert!(client.is_quote_stale(&quote));
+    }
+
+    fn sample_quote() -> QuoteResponse {
+        QuoteResponse {
+            amount: "0.5".to_string(),
+            chain: "solana".to_string(),
+            decimals: 6,
+            expires_at: now_unix() + 60,
+            mint: "mint".to_string(),
+            network: "devnet".to_string(),
+            nonce: "nonce".to_string(),
+            recipient: "recipient".to_string(),
+            version: "1".to_string(),
+        }
+    }
 }
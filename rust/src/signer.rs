@@ -0,0 +1,250 @@
+//! Local transaction signing and submission for the SPL-token transfer a
+//! [`QuoteResponse`](crate::QuoteResponse) describes, following the same
+//! "load a keypair, build the instruction, sign, submit" shape used by most
+//! Solana payment-client integrations built on ed25519-dalek.
+
+use crate::{HttpMethod, HttpTransport, Nlx402Error, QuoteResponse, RequestBody};
+use ed25519_dalek::{Keypair, Signer as _};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::Signature,
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use std::str::FromStr;
+
+/// Holds the ed25519 keypair used to sign the on-chain SPL-token transfer
+/// that satisfies a quote. Construct it from the raw bytes of a Solana
+/// keypair file (the same 64-byte secret+public layout `solana-keygen`
+/// produces).
+pub struct Nlx402Signer {
+    keypair: Keypair,
+}
+
+impl Nlx402Signer {
+    /// Load a signer from a 64-byte ed25519 keypair (secret key || public key).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Nlx402Error> {
+        let keypair = Keypair::from_bytes(bytes)
+            .map_err(|e| Nlx402Error::Signer(format!("invalid keypair bytes: {}", e)))?;
+        Ok(Self { keypair })
+    }
+
+    pub fn pubkey(&self) -> Pubkey {
+        Pubkey::new_from_array(self.keypair.public.to_bytes())
+    }
+
+    /// Build, sign, and submit the SPL-token transfer described by `quote`,
+    /// returning the transaction signature once the RPC node has accepted it.
+    /// RPC calls are issued through `transport` (the caller's `Nlx402Client`
+    /// transport) rather than a hard-coded HTTP client, so signing works the
+    /// same way under any `HttpTransport` the client was configured with.
+    pub(crate) async fn sign_and_submit(
+        &self,
+        transport: &dyn HttpTransport,
+        quote: &QuoteResponse,
+    ) -> Result<String, Nlx402Error> {
+        let rpc_url = rpc_endpoint_for(&quote.chain, &quote.network)?;
+
+        let recipient = Pubkey::from_str(&quote.recipient)
+            .map_err(|e| Nlx402Error::Signer(format!("invalid recipient: {}", e)))?;
+        let mint = Pubkey::from_str(&quote.mint)
+            .map_err(|e| Nlx402Error::Signer(format!("invalid mint: {}", e)))?;
+        let owner = self.pubkey();
+
+        let source = get_associated_token_address(&owner, &mint);
+        let destination = get_associated_token_address(&recipient, &mint);
+        let base_units = scale_to_base_units(&quote.amount, quote.decimals)?;
+
+        let transfer_ix = spl_token::instruction::transfer_checked(
+            &spl_token::id(),
+            &source,
+            &mint,
+            &destination,
+            &owner,
+            &[],
+            base_units,
+            quote.decimals as u8,
+        )
+        .map_err(|e| Nlx402Error::Signer(format!("failed to build transfer instruction: {}", e)))?;
+
+        let memo_ix = spl_memo::build_memo(quote.nonce.as_bytes(), &[&owner]);
+
+        let recent_blockhash = rpc_get_latest_blockhash(transport, &rpc_url).await?;
+        let mut tx = Transaction::new_with_payer(&[transfer_ix, memo_ix], Some(&owner));
+        tx.sign(&[&SignerAdapter(&self.keypair)], recent_blockhash);
+
+        rpc_send_transaction(transport, &rpc_url, &tx).await
+    }
+}
+
+/// Adapts an `ed25519_dalek::Keypair` to the `solana_sdk::signer::Signer`
+/// trait so it can be handed directly to `Transaction::sign`.
+struct SignerAdapter<'a>(&'a Keypair);
+
+impl<'a> solana_sdk::signer::Signer for SignerAdapter<'a> {
+    fn pubkey(&self) -> Pubkey {
+        Pubkey::new_from_array(self.0.public.to_bytes())
+    }
+
+    fn try_pubkey(&self) -> Result<Pubkey, solana_sdk::signer::SignerError> {
+        Ok(self.pubkey())
+    }
+
+    fn sign_message(&self, message: &[u8]) -> Signature {
+        Signature::from(self.0.sign(message).to_bytes())
+    }
+
+    fn try_sign_message(
+        &self,
+        message: &[u8],
+    ) -> Result<Signature, solana_sdk::signer::SignerError> {
+        Ok(self.sign_message(message))
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
+
+/// Resolves the `chain`/`network` pair on a quote to a JSON-RPC endpoint.
+/// Only Solana mainnet/devnet/testnet are known today; anything else is
+/// rejected rather than silently guessed.
+fn rpc_endpoint_for(chain: &str, network: &str) -> Result<String, Nlx402Error> {
+    if !chain.eq_ignore_ascii_case("solana") {
+        return Err(Nlx402Error::Signer(format!("unsupported chain: {}", chain)));
+    }
+    let url = match network.to_ascii_lowercase().as_str() {
+        "mainnet" | "mainnet-beta" => "https://api.mainnet-beta.solana.com",
+        "devnet" => "https://api.devnet.solana.com",
+        "testnet" => "https://api.testnet.solana.com",
+        other => {
+            return Err(Nlx402Error::Signer(format!(
+                "unsupported network: {}",
+                other
+            )))
+        }
+    };
+    Ok(url.to_string())
+}
+
+/// Converts a decimal amount string (e.g. `"0.5"`) plus a scale into the
+/// integer base-unit count SPL transfers expect, without going through
+/// floating point.
+fn scale_to_base_units(amount: &str, decimals: u32) -> Result<u64, Nlx402Error> {
+    let (whole, frac) = match amount.split_once('.') {
+        Some((w, f)) => (w, f),
+        None => (amount, ""),
+    };
+    if frac.len() as u32 > decimals {
+        return Err(Nlx402Error::Signer(format!(
+            "amount {} has more precision than {} decimals",
+            amount, decimals
+        )));
+    }
+    let padded_frac = format!("{:0<width$}", frac, width = decimals as usize);
+    let digits = format!("{}{}", whole, padded_frac);
+    digits
+        .parse::<u64>()
+        .map_err(|e| Nlx402Error::Signer(format!("invalid amount {}: {}", amount, e)))
+}
+
+async fn rpc_get_latest_blockhash(
+    transport: &dyn HttpTransport,
+    rpc_url: &str,
+) -> Result<solana_sdk::hash::Hash, Nlx402Error> {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "getLatestBlockhash",
+        "params": [{ "commitment": "confirmed" }],
+    });
+
+    let response = transport
+        .execute(HttpMethod::Post, rpc_url, &[], Some(RequestBody::Json(body)))
+        .await?;
+    let value: serde_json::Value = serde_json::from_str(&response.body)
+        .map_err(|e| Nlx402Error::InvalidResponse(e.to_string()))?;
+
+    let blockhash = value
+        .get("result")
+        .and_then(|r| r.get("value"))
+        .and_then(|v| v.get("blockhash"))
+        .and_then(|b| b.as_str())
+        .ok_or_else(|| Nlx402Error::Signer("RPC response missing blockhash".into()))?;
+
+    solana_sdk::hash::Hash::from_str(blockhash)
+        .map_err(|e| Nlx402Error::Signer(format!("invalid blockhash: {}", e)))
+}
+
+async fn rpc_send_transaction(
+    transport: &dyn HttpTransport,
+    rpc_url: &str,
+    tx: &Transaction,
+) -> Result<String, Nlx402Error> {
+    let serialized = bincode::serialize(tx)
+        .map_err(|e| Nlx402Error::Signer(format!("failed to serialize transaction: {}", e)))?;
+    let encoded = {
+        use base64::Engine as _;
+        base64::engine::general_purpose::STANDARD.encode(serialized)
+    };
+
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "sendTransaction",
+        "params": [encoded, { "encoding": "base64" }],
+    });
+
+    let response = transport
+        .execute(HttpMethod::Post, rpc_url, &[], Some(RequestBody::Json(body)))
+        .await?;
+    let value: serde_json::Value = serde_json::from_str(&response.body)
+        .map_err(|e| Nlx402Error::InvalidResponse(e.to_string()))?;
+
+    if let Some(err) = value.get("error") {
+        return Err(Nlx402Error::Signer(format!("sendTransaction failed: {}", err)));
+    }
+
+    value
+        .get("result")
+        .and_then(|r| r.as_str())
+        .map(|s| s.to_string())
+        .ok_or_else(|| Nlx402Error::Signer("RPC response missing signature".into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_to_base_units_pads_and_scales() {
+        assert_eq!(scale_to_base_units("0.5", 6).unwrap(), 500_000);
+        assert_eq!(scale_to_base_units("1", 6).unwrap(), 1_000_000);
+        assert_eq!(scale_to_base_units("1.23", 2).unwrap(), 123);
+        assert_eq!(scale_to_base_units("0", 9).unwrap(), 0);
+    }
+
+    #[test]
+    fn scale_to_base_units_rejects_excess_precision() {
+        assert!(scale_to_base_units("0.12345", 2).is_err());
+    }
+
+    #[test]
+    fn scale_to_base_units_rejects_non_numeric_input() {
+        assert!(scale_to_base_units("abc", 6).is_err());
+    }
+
+    #[test]
+    fn rpc_endpoint_for_rejects_unsupported_chain() {
+        assert!(rpc_endpoint_for("ethereum", "mainnet").is_err());
+    }
+
+    #[test]
+    fn rpc_endpoint_for_resolves_known_solana_networks() {
+        assert_eq!(
+            rpc_endpoint_for("solana", "devnet").unwrap(),
+            "https://api.devnet.solana.com"
+        );
+        assert!(rpc_endpoint_for("solana", "unknown-net").is_err());
+    }
+}
@@ -0,0 +1,124 @@
+//! Pluggable HTTP transport, so callers aren't locked into the bundled
+//! reqwest client. Mirrors the abstraction most SDKs grow once they need to
+//! support more than one HTTP backend (tracing middleware, custom TLS,
+//! proxies, or a test double in place of a real network call).
+
+use crate::Nlx402Error;
+use std::time::Duration;
+
+/// HTTP method used by a transport-agnostic request. Only the methods this
+/// SDK actually issues are modeled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpMethod {
+    Get,
+    Post,
+}
+
+/// Body of a transport-agnostic request. Only the shapes this SDK sends
+/// today are modeled; add a variant here before reaching for a raw body.
+#[derive(Debug, Clone)]
+pub enum RequestBody {
+    Form(Vec<(String, String)>),
+    /// A JSON-RPC (or other JSON) request body, used by `Nlx402Signer` to
+    /// talk to the Solana RPC endpoint through the same transport.
+    Json(serde_json::Value),
+}
+
+/// Response returned by a transport: the status, the body text, and a
+/// `Retry-After` hint if the response carried one. `Nlx402Client` handles
+/// JSON decoding and retries on top of this.
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: http::StatusCode,
+    pub body: String,
+    /// Parsed `Retry-After` header, in seconds-or-HTTP-date form, as a
+    /// ready-to-sleep `Duration`. `None` when the response didn't carry one.
+    pub retry_after: Option<Duration>,
+}
+
+/// Implemented by whatever actually moves bytes over the network. Response
+/// headers other than `Retry-After` are not part of this boundary; a
+/// transport that needs to honor other headers should do so internally
+/// before returning.
+#[async_trait::async_trait]
+pub trait HttpTransport: Send + Sync {
+    async fn execute(
+        &self,
+        method: HttpMethod,
+        url: &str,
+        headers: &[(&str, String)],
+        body: Option<RequestBody>,
+    ) -> Result<HttpResponse, Nlx402Error>;
+}
+
+/// Default transport backed by `reqwest`. Enabled by the `reqwest-transport`
+/// feature, which is on by default.
+#[cfg(feature = "reqwest-transport")]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "reqwest-transport")]
+impl ReqwestTransport {
+    pub fn new() -> Self {
+        ReqwestTransport {
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[cfg(feature = "reqwest-transport")]
+impl Default for ReqwestTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "reqwest-transport")]
+#[async_trait::async_trait]
+impl HttpTransport for ReqwestTransport {
+    async fn execute(
+        &self,
+        method: HttpMethod,
+        url: &str,
+        headers: &[(&str, String)],
+        body: Option<RequestBody>,
+    ) -> Result<HttpResponse, Nlx402Error> {
+        let mut builder = match method {
+            HttpMethod::Get => self.client.get(url),
+            HttpMethod::Post => self.client.post(url),
+        };
+        for (name, value) in headers {
+            builder = builder.header(*name, value);
+        }
+        match body {
+            Some(RequestBody::Form(fields)) => builder = builder.form(&fields),
+            Some(RequestBody::Json(value)) => builder = builder.json(&value),
+            None => {}
+        }
+
+        let res = builder.send().await.map_err(Nlx402Error::Http)?;
+        let status = res.status();
+        let retry_after = parse_retry_after(res.headers());
+        let text = res.text().await.map_err(Nlx402Error::Http)?;
+        Ok(HttpResponse {
+            status,
+            body: text,
+            retry_after,
+        })
+    }
+}
+
+/// Parses a `Retry-After` header value, which per RFC 9110 is either a
+/// number of seconds or an HTTP-date, into a `Duration` to sleep.
+#[cfg(feature = "reqwest-transport")]
+fn parse_retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let value = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let target = httpdate::parse_http_date(value).ok()?;
+    target.duration_since(std::time::SystemTime::now()).ok()
+}